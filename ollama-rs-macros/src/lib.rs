@@ -0,0 +1,170 @@
+//! Derive macros for [`ollama_rs::generation::tools::Tool`](https://docs.rs/ollama-rs).
+//!
+//! `ollama-rs` re-exports these under its `derive` feature, so most users should depend on
+//! `ollama-rs` with that feature enabled rather than on this crate directly.
+
+use darling::{ast::NestedMeta, FromMeta};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse::Parse, Data, DeriveInput, Fields};
+
+/// Generates a `Tool` impl from a struct.
+///
+/// ```ignore
+/// #[derive(Tool)]
+/// #[tool(name = "get_weather", description = "Get the weather for a city", params = GetWeatherParams)]
+/// struct GetWeather;
+///
+/// impl GetWeather {
+///     async fn call_tool(&mut self, params: GetWeatherParams) -> ollama_rs::generation::tools::Result<String> {
+///         Ok(format!("sunny in {}", params.city))
+///     }
+/// }
+/// ```
+///
+/// `params` must name a type that implements `Deserialize + JsonSchema` *and* derives
+/// [`macro@ToolParams`] — this derive emits a `Params: ParamsDescribed` assertion, so forgetting
+/// `#[derive(ToolParams)]` on `params` (or a field on it missing its
+/// `#[schemars(description = ...)]`) is a compile error here, not a silently-accepted gap.
+#[proc_macro_derive(Tool, attributes(tool))]
+pub fn derive_tool(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let meta = match tool_attrs(&input) {
+        Ok(meta) => meta,
+        Err(err) => return err.write_errors().into(),
+    };
+
+    let ToolArgs {
+        name,
+        description,
+        params,
+    } = meta;
+
+    let assert_params_described = quote! {
+        const _: fn() = || {
+            fn assert_params_described<P: ::ollama_rs::generation::tools::ParamsDescribed>() {}
+            assert_params_described::<#params>();
+        };
+    };
+
+    let expanded = quote! {
+        impl ::ollama_rs::generation::tools::Tool for #ident {
+            type Params = #params;
+
+            fn name() -> &'static str {
+                #name
+            }
+
+            fn description() -> &'static str {
+                #description
+            }
+
+            fn call(
+                &mut self,
+                parameters: Self::Params,
+            ) -> impl ::core::future::Future<Output = ::ollama_rs::generation::tools::Result<String>> + Send + Sync {
+                #ident::call_tool(self, parameters)
+            }
+        }
+
+        #assert_params_described
+    };
+
+    expanded.into()
+}
+
+#[derive(FromMeta)]
+struct ToolArgs {
+    name: String,
+    description: String,
+    params: syn::Path,
+}
+
+fn tool_attrs(input: &DeriveInput) -> Result<ToolArgs, darling::Error> {
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("tool"))
+        .ok_or_else(|| {
+            darling::Error::custom(
+                "missing #[tool(name = \"...\", description = \"...\", params = ...)] attribute",
+            )
+            .with_span(&input.ident)
+        })?;
+
+    let meta_list = attr.meta.require_list()?;
+    let nested = NestedMeta::parse_meta_list(meta_list.tokens.clone())?;
+    ToolArgs::from_list(&nested)
+}
+
+/// Enforces that every field of a tool's `Params` struct documents itself with
+/// `#[schemars(description = "...")]`, which the JSON schema generated by `ToolInfo::new`
+/// needs in order to describe arguments to the model.
+///
+/// When every field has a description, this derive emits only an impl of the
+/// `ParamsDescribed` marker trait, which `#[derive(Tool)]` checks for; when a field is missing
+/// one, it fails the build instead.
+#[proc_macro_derive(ToolParams)]
+pub fn derive_tool_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    ident,
+                    "ToolParams only supports structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(ident, "ToolParams can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut errors = Vec::new();
+    for field in fields {
+        let has_description = field.attrs.iter().any(|attr| {
+            attr.path().is_ident("schemars")
+                && attr
+                    .parse_args_with(|input: syn::parse::ParseStream| {
+                        let nested = input.parse_terminated(syn::Meta::parse, syn::Token![,])?;
+                        Ok(nested.iter().any(|meta| {
+                            meta.path().is_ident("description") && meta.require_name_value().is_ok()
+                        }))
+                    })
+                    .unwrap_or(false)
+        });
+
+        if !has_description {
+            let field_ident = field.ident.as_ref().expect("named field");
+            errors.push(
+                syn::Error::new_spanned(
+                    field_ident,
+                    format!(
+                        "field `{field_ident}` is missing #[schemars(description = \"...\")], \
+                         which Tool parameter schemas require"
+                    ),
+                )
+                .to_compile_error(),
+            );
+        }
+    }
+
+    if !errors.is_empty() {
+        return quote! { #(#errors)* }.into();
+    }
+
+    quote! {
+        impl ::ollama_rs::generation::tools::ParamsDescribed for #ident {}
+    }
+    .into()
+}
@@ -0,0 +1,34 @@
+// Fixture for the `trybuild` compile-fail harness in `tests/trybuild.rs`, run via
+// `TestCases::compile_fail`. `ollama_rs` here is `tests/support`, a minimal stand-in for the real
+// crate (see that crate's doc comment) since this checkout has no published `ollama-rs` manifest
+// to depend on.
+//
+// `ollama_rs::generation::tools` re-exports this crate's `Tool` derive alongside its `Tool` trait
+// (as the real crate does under its `derive` feature), so importing the trait also brings the derive into scope — a
+// separate `use ollama_rs_macros::Tool` here would import the derive a second time and collide
+// (E0252).
+use ollama_rs::generation::tools::Tool;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// `Params` implements `Deserialize + JsonSchema` but never derives `ToolParams`, so it has no
+// per-field `#[schemars(description = ...)]` enforcement. `#[derive(Tool)]` must reject this.
+#[derive(Deserialize, JsonSchema)]
+struct Params {
+    city: String,
+}
+
+#[derive(Tool)]
+#[tool(name = "get_weather", description = "Get the weather for a city", params = Params)]
+struct GetWeather;
+
+impl GetWeather {
+    async fn call_tool(
+        &mut self,
+        _params: Params,
+    ) -> ollama_rs::generation::tools::Result<String> {
+        Ok(String::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+//! Minimal stand-in for `ollama_rs::generation::tools`, used only by this crate's `trybuild`
+//! compile-fail tests. There's no published `Cargo.toml` for the real `ollama-rs` crate in this
+//! checkout to depend on, so this mirrors the handful of items `#[derive(Tool)]`'s expansion and
+//! the compile-fail fixtures reference — `Tool`, `ParamsDescribed`, `Parameters`, `Result` — plus
+//! the same `pub use ollama_rs_macros::{Tool, ToolParams}` re-export the real crate's `derive`
+//! feature does, so fixtures hit the exact same macro/trait name collision a real downstream
+//! crate would. Keep it in sync with `ollama-rs/src/generation/tools/mod.rs` if those items
+//! change shape.
+
+pub mod generation {
+    pub mod tools {
+        use std::future::Future;
+
+        use schemars::JsonSchema;
+        use serde::de::DeserializeOwned;
+
+        pub use ollama_rs_macros::{Tool, ToolParams};
+
+        pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+        pub trait ParamsDescribed {}
+
+        pub trait Parameters: DeserializeOwned + JsonSchema {}
+
+        impl<P: DeserializeOwned + JsonSchema> Parameters for P {}
+
+        pub trait Tool: Send + Sync {
+            type Params: Parameters;
+
+            fn name() -> &'static str;
+            fn description() -> &'static str;
+
+            const VALIDATE_ARGUMENTS: bool = false;
+
+            fn call(
+                &mut self,
+                parameters: Self::Params,
+            ) -> impl Future<Output = Result<String>> + Send + Sync;
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use schemars::generate::SchemaSettings;
+use serde_json::Value;
+
+/// Which JSON Schema draft (and subschema inlining strategy) to generate a tool's parameter
+/// schema with, passed to [`super::ToolInfo::with_settings`].
+///
+/// Ollama accepts draft-07 schemas with `$defs` inlined, which is what [`super::ToolInfo::new`]
+/// has always produced and remains the default here. Some OpenAI-compatible backends expect
+/// draft 2020-12 and/or `$ref`-based subschemas instead.
+#[derive(Clone, Copy, Debug)]
+pub enum SchemaDialect {
+    /// JSON Schema draft-07, as used by Ollama.
+    Draft07 {
+        /// Whether `$defs` subschemas are inlined directly rather than referenced with `$ref`.
+        inline_subschemas: bool,
+    },
+    /// JSON Schema draft 2020-12, as expected by some OpenAI-compatible servers.
+    Draft202012 {
+        /// Whether `$defs` subschemas are inlined directly rather than referenced with `$ref`.
+        inline_subschemas: bool,
+    },
+}
+
+impl Default for SchemaDialect {
+    fn default() -> Self {
+        Self::Draft07 {
+            inline_subschemas: true,
+        }
+    }
+}
+
+impl SchemaDialect {
+    pub(crate) fn into_settings(self) -> SchemaSettings {
+        let (mut settings, inline_subschemas) = match self {
+            Self::Draft07 { inline_subschemas } => (SchemaSettings::draft07(), inline_subschemas),
+            Self::Draft202012 { inline_subschemas } => {
+                (SchemaSettings::draft2020_12(), inline_subschemas)
+            }
+        };
+        settings.inline_subschemas = inline_subschemas;
+        settings
+    }
+}
+
+/// Strips JSON-schema keywords that tool-calling endpoints commonly reject or mishandle:
+/// `$schema` (servers expect a bare schema, not a meta-schema reference) and `format` (many
+/// endpoints only understand the `type`/`properties`/`required` subset). Object schemas with no
+/// `additionalProperties` get `additionalProperties: false` added (several OpenAI-compatible
+/// servers require it to be explicit); an existing value is left as it is.
+pub(crate) fn sanitize_for_tool_calling(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("$schema");
+            map.remove("format");
+
+            if map.get("type").and_then(Value::as_str) == Some("object")
+                && !map.contains_key("additionalProperties")
+            {
+                map.insert("additionalProperties".to_string(), Value::Bool(false));
+            }
+
+            for value in map.values_mut() {
+                sanitize_for_tool_calling(value);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                sanitize_for_tool_calling(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn strips_schema_and_format_keywords() {
+        let mut value = json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {
+                "when": { "type": "string", "format": "date-time" }
+            }
+        });
+
+        sanitize_for_tool_calling(&mut value);
+
+        assert!(value.get("$schema").is_none());
+        assert!(value["properties"]["when"].get("format").is_none());
+    }
+
+    #[test]
+    fn fills_in_missing_additional_properties() {
+        let mut value = json!({ "type": "object", "properties": {} });
+        sanitize_for_tool_calling(&mut value);
+        assert_eq!(value["additionalProperties"], json!(false));
+    }
+
+    #[test]
+    fn leaves_explicit_additional_properties_alone() {
+        let mut value = json!({ "type": "object", "additionalProperties": true });
+        sanitize_for_tool_calling(&mut value);
+        assert_eq!(value["additionalProperties"], json!(true));
+    }
+}
@@ -0,0 +1,197 @@
+use schemars::Schema;
+use serde_json::{json, Value};
+
+use super::{ToolChoice, ToolInfo};
+
+/// Builds a constrained-decoding schema (suitable for Ollama's `format` field) that pins the
+/// shape of a tool call: a `function.name` property limited to the registered tool names, and a
+/// `function.arguments` property matching the chosen tool's `parameters` schema.
+///
+/// This makes tool use reliable even on models with no built-in function-calling support: the
+/// model is constrained to emit a schema that deserializes straight into [`super::ToolCall`]
+/// (`{"function": {"name": ..., "arguments": ...}}`) rather than free text.
+pub struct ToolGrammar;
+
+impl ToolGrammar {
+    /// Build a grammar covering every tool in `tools`.
+    ///
+    /// With a single tool, `function.name` is pinned with `const` so the output is guaranteed
+    /// aligned. With several, `function.name` is an `enum` of all names and a top-level `oneOf`
+    /// keys each branch's `function.arguments` subschema to its tool's `const` name.
+    pub fn from_tools(tools: &[ToolInfo]) -> super::Result<Schema> {
+        if tools.is_empty() {
+            return Err("cannot build a tool grammar from an empty tool list".into());
+        }
+
+        let value = if let [tool] = tools {
+            single_tool_schema(tool)
+        } else {
+            multi_tool_schema(tools)
+        };
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Build a grammar honoring `choice`. [`ToolChoice::Specific`] narrows the grammar to just
+    /// that tool (pinning `function.name` with `const`); [`ToolChoice::Auto`] and
+    /// [`ToolChoice::Required`] fall back to [`Self::from_tools`]. [`ToolChoice::None`] is an
+    /// error, since there is no tool-call shape to constrain when tools are disabled.
+    pub fn from_tools_with_choice(
+        tools: &[ToolInfo],
+        choice: &ToolChoice,
+    ) -> super::Result<Schema> {
+        match choice {
+            ToolChoice::None => {
+                Err("cannot build a tool grammar when ToolChoice::None disables tool use".into())
+            }
+            ToolChoice::Specific { name } => {
+                let tool = tools
+                    .iter()
+                    .find(|tool| &tool.function.name == name)
+                    .ok_or_else(|| {
+                        format!("tool choice `{name}` does not match any registered tool")
+                    })?;
+
+                Self::from_tools(std::slice::from_ref(tool))
+            }
+            ToolChoice::Auto | ToolChoice::Required => Self::from_tools(tools),
+        }
+    }
+}
+
+fn function_schema(tool: &ToolInfo) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "name": { "const": tool.function.name },
+            "arguments": tool.function.parameters,
+        },
+        "required": ["name", "arguments"],
+    })
+}
+
+fn single_tool_schema(tool: &ToolInfo) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "function": function_schema(tool),
+        },
+        "required": ["function"],
+    })
+}
+
+fn multi_tool_schema(tools: &[ToolInfo]) -> Value {
+    let names: Vec<&str> = tools.iter().map(|tool| tool.function.name.as_str()).collect();
+
+    let branches: Vec<Value> = tools
+        .iter()
+        .map(|tool| {
+            json!({
+                "properties": {
+                    "function": function_schema(tool),
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "object",
+        "properties": {
+            "function": {
+                "type": "object",
+                "properties": {
+                    "name": { "enum": names },
+                },
+                "required": ["name", "arguments"],
+            },
+        },
+        "required": ["function"],
+        "oneOf": branches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::tools::{ToolCall, ToolFunctionInfo};
+
+    fn tool_info(name: &str) -> ToolInfo {
+        let schema_value = json!({
+            "type": "object",
+            "properties": { "city": { "type": "string" } },
+            "required": ["city"],
+        });
+        ToolInfo {
+            tool_type: super::super::ToolType::Function,
+            function: ToolFunctionInfo {
+                name: name.to_string(),
+                description: "a test tool".to_string(),
+                parameters: serde_json::from_value(schema_value).unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn single_tool_pins_name_with_const() {
+        let tools = vec![tool_info("get_weather")];
+        let schema = ToolGrammar::from_tools(&tools).unwrap();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"]["function"]["properties"]["name"]["const"],
+            "get_weather"
+        );
+    }
+
+    #[test]
+    fn single_tool_grammar_shape_round_trips_into_tool_call() {
+        let tools = vec![tool_info("get_weather")];
+        let schema = ToolGrammar::from_tools(&tools).unwrap();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert!(value["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("function")));
+
+        let sample = json!({
+            "function": { "name": "get_weather", "arguments": { "city": "Berlin" } }
+        });
+        let call: ToolCall = serde_json::from_value(sample).unwrap();
+        assert_eq!(call.function.name, "get_weather");
+    }
+
+    #[test]
+    fn multi_tool_uses_enum_and_one_of() {
+        let tools = vec![tool_info("get_weather"), tool_info("get_forecast")];
+        let schema = ToolGrammar::from_tools(&tools).unwrap();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"]["function"]["properties"]["name"]["enum"],
+            json!(["get_weather", "get_forecast"])
+        );
+        assert_eq!(value["oneOf"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn empty_tool_list_is_rejected() {
+        assert!(ToolGrammar::from_tools(&[]).is_err());
+    }
+
+    #[test]
+    fn specific_choice_narrows_to_one_tool() {
+        let tools = vec![tool_info("get_weather"), tool_info("get_forecast")];
+        let schema =
+            ToolGrammar::from_tools_with_choice(&tools, &ToolChoice::specific("get_forecast"))
+                .unwrap();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            value["properties"]["function"]["properties"]["name"]["const"],
+            "get_forecast"
+        );
+    }
+
+    #[test]
+    fn none_choice_is_rejected() {
+        let tools = vec![tool_info("get_weather")];
+        assert!(ToolGrammar::from_tools_with_choice(&tools, &ToolChoice::None).is_err());
+    }
+}
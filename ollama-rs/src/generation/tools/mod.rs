@@ -2,6 +2,22 @@
 #[cfg(feature = "tool-implementations")]
 pub mod implementations;
 
+mod dialect;
+mod grammar;
+mod tool_choice;
+#[cfg(feature = "schema-validation")]
+mod validation;
+
+pub use dialect::SchemaDialect;
+pub use grammar::ToolGrammar;
+pub use tool_choice::ToolChoice;
+
+/// Derives a [`Tool`] impl, and a derive that enforces per-attribute descriptions on a `Params`
+/// struct, available under the `derive` feature.
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use ollama_rs_macros::{Tool, ToolParams};
+
 use std::{future::Future, pin::Pin};
 
 use schemars::{generate::SchemaSettings, JsonSchema, Schema};
@@ -10,15 +26,29 @@ use serde_json::Value;
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
+/// Marker trait confirming a `Params` struct's fields all carry a `#[schemars(description = ...)]`
+/// attribute. Implemented by `#[derive(ToolParams)]` (see the `derive` feature); the derive
+/// fails to compile instead of implementing this trait when a field is missing its description.
+#[cfg(feature = "derive")]
+pub trait ParamsDescribed {}
+
 /// It's highly recommended that the `JsonSchema` has descriptions for all attributes.
-/// Descriptions can be defined with `#[schemars(description = "Hi I am an attribute")]` above each attribute
-// TODO enforce at compile-time
+/// Descriptions can be defined with `#[schemars(description = "Hi I am an attribute")]` above each attribute.
+/// Enable the `derive` feature and use `#[derive(Tool)]` with `#[derive(ToolParams)]` on `Params`
+/// to enforce this at compile time instead of relying on convention.
 pub trait Tool: Send + Sync {
     type Params: Parameters;
 
     fn name() -> &'static str;
     fn description() -> &'static str;
 
+    /// Whether arguments returned by the model should be validated against this tool's JSON
+    /// schema before `call` runs. Requires the `schema-validation` feature; a no-op without it.
+    ///
+    /// Opt in for tools where a malformed call should come back to the model as a structured,
+    /// self-correctable error instead of a raw serde deserialization failure.
+    const VALIDATE_ARGUMENTS: bool = false;
+
     /// Call the tool.
     /// Note that returning an Err will cause it to be bubbled up. If you want the LLM to handle the error,
     /// return that error as a string.
@@ -56,6 +86,12 @@ impl<T: Tool> ToolHolder for T {
                 },
             };
 
+            #[cfg(feature = "schema-validation")]
+            if T::VALIDATE_ARGUMENTS {
+                let schema = ToolInfo::new::<T::Params, T>().function.parameters;
+                validation::validate_arguments(&schema, &param_value)?;
+            }
+
             let param = serde_json::from_value(param_value)?;
 
             T::call(self, param).await
@@ -142,6 +178,11 @@ pub struct ToolInfo {
 }
 
 impl ToolInfo {
+    /// Create a `ToolInfo` the way this crate always has: draft-07, with `$defs` inlined, and no
+    /// post-processing of the generated schema. Kept byte-for-byte unchanged so existing callers
+    /// relying on this default (e.g. a hand-rolled `JsonSchema` impl that allows extra properties)
+    /// don't have schema keywords added or removed out from under them. Use
+    /// [`Self::with_settings`] to opt into a different dialect or tool-calling sanitization.
     pub(crate) fn new<P: Parameters, T: Tool<Params = P>>() -> Self {
         let mut settings = SchemaSettings::draft07();
         settings.inline_subschemas = true;
@@ -158,6 +199,34 @@ impl ToolInfo {
             },
         }
     }
+
+    /// Create a `ToolInfo`, generating the parameter schema with the given [`SchemaDialect`] and
+    /// then adapting it for tool-calling endpoints (see [`dialect::sanitize_for_tool_calling`]):
+    /// `$schema` and `format` are stripped, and object schemas that leave
+    /// `additionalProperties` implicit get an explicit `additionalProperties: false`.
+    ///
+    /// This is additive: unlike [`Self::new`], which callers already depend on for an
+    /// unmodified draft-07 schema, this path is opt-in for backends that need a different draft
+    /// or that reject the keywords above.
+    pub fn with_settings<P: Parameters, T: Tool<Params = P>>(dialect: SchemaDialect) -> Self {
+        let generator = dialect.into_settings().into_generator();
+        let parameters = generator.into_root_schema_for::<P>();
+
+        let mut value =
+            serde_json::to_value(&parameters).expect("a generated JSON schema serializes");
+        dialect::sanitize_for_tool_calling(&mut value);
+        let parameters: Schema =
+            serde_json::from_value(value).expect("a sanitized JSON schema deserializes");
+
+        Self {
+            tool_type: ToolType::Function,
+            function: ToolFunctionInfo {
+                name: T::name().to_string(),
+                description: T::description().to_string(),
+                parameters,
+            },
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -254,4 +323,95 @@ mod tests {
         assert_eq!(json["function"]["name"], "get_weather");
         assert_eq!(json["function"]["description"], "Get the weather in a given city");
     }
+
+    #[derive(Deserialize, JsonSchema)]
+    struct DummyParams {
+        city: String,
+    }
+
+    struct DummyTool;
+
+    impl Tool for DummyTool {
+        type Params = DummyParams;
+
+        fn name() -> &'static str {
+            "dummy"
+        }
+
+        fn description() -> &'static str {
+            "a dummy tool for schema generation tests"
+        }
+
+        async fn call(&mut self, _parameters: Self::Params) -> Result<String> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn new_does_not_add_additional_properties() {
+        let info = ToolInfo::new::<DummyParams, DummyTool>();
+        let value = serde_json::to_value(&info.function.parameters).unwrap();
+        assert!(value.get("additionalProperties").is_none());
+    }
+
+    #[test]
+    fn with_settings_sanitizes_but_new_is_unchanged() {
+        let plain = ToolInfo::new::<DummyParams, DummyTool>();
+        let sanitized = ToolInfo::with_settings::<DummyParams, DummyTool>(SchemaDialect::default());
+
+        let plain_value = serde_json::to_value(&plain.function.parameters).unwrap();
+        let sanitized_value = serde_json::to_value(&sanitized.function.parameters).unwrap();
+
+        assert!(plain_value.get("additionalProperties").is_none());
+        assert_eq!(sanitized_value["additionalProperties"], serde_json::json!(false));
+    }
+}
+
+/// End-to-end coverage of [`Tool::VALIDATE_ARGUMENTS`] through [`ToolHolder::call`], not just
+/// the schema check in isolation.
+#[cfg(all(test, feature = "schema-validation"))]
+mod schema_validation_tests {
+    use super::*;
+
+    #[derive(Deserialize, JsonSchema)]
+    struct StrictParams {
+        #[schemars(description = "the city to look up")]
+        city: String,
+    }
+
+    struct StrictTool;
+
+    impl Tool for StrictTool {
+        type Params = StrictParams;
+
+        const VALIDATE_ARGUMENTS: bool = true;
+
+        fn name() -> &'static str {
+            "strict_tool"
+        }
+
+        fn description() -> &'static str {
+            "a tool that validates its arguments before running"
+        }
+
+        async fn call(&mut self, parameters: Self::Params) -> Result<String> {
+            Ok(parameters.city)
+        }
+    }
+
+    #[tokio::test]
+    async fn call_runs_when_arguments_match_the_schema() {
+        let mut tool = StrictTool;
+        let result = ToolHolder::call(&mut tool, serde_json::json!({ "city": "Berlin" })).await;
+        assert_eq!(result.unwrap(), "Berlin");
+    }
+
+    #[tokio::test]
+    async fn call_rejects_arguments_that_fail_the_schema() {
+        let mut tool = StrictTool;
+        let err = ToolHolder::call(&mut tool, serde_json::json!({ "city": 5 }))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("did not match the expected schema"));
+    }
 }
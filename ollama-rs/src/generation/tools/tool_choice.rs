@@ -0,0 +1,176 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::ToolInfo;
+
+/// Controls how (or whether) the model is allowed to call tools for a single request.
+///
+/// Serializes to the shape Ollama/OpenAI-compatible servers expect: `Auto`, `None` and
+/// `Required` are plain strings, while `Specific` serializes to
+/// `{"type":"function","function":{"name":"..."}}`.
+///
+/// Not wired into a request yet — there's no chat request builder in this slice of the
+/// codebase to thread a `tool_choice` field into.
+// FIXME
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ToolChoice {
+    /// Let the model decide whether to call a tool. This is the default server behavior.
+    Auto,
+    /// Forbid tool calls for this request.
+    None,
+    /// Force the model to call a tool, without pinning which one.
+    Required,
+    /// Force the model to call the named tool.
+    Specific {
+        /// Must match the `name` of a [`ToolInfo`] registered with the request, see [`ToolChoice::validate`].
+        name: String,
+    },
+}
+
+impl ToolChoice {
+    /// Force the model to call the tool with the given name.
+    pub fn specific(name: impl Into<String>) -> Self {
+        Self::Specific { name: name.into() }
+    }
+
+    /// Check that a [`ToolChoice::Specific`] choice refers to one of the given tools.
+    ///
+    /// Fails fast on a typo'd tool name instead of the server silently ignoring the choice.
+    /// Always succeeds for `Auto`, `None` and `Required`.
+    pub fn validate(&self, tools: &[ToolInfo]) -> super::Result<()> {
+        if let Self::Specific { name } = self {
+            if !tools.iter().any(|tool| &tool.function.name == name) {
+                return Err(format!(
+                    "tool choice `{name}` does not match any registered tool (available: {})",
+                    tools
+                        .iter()
+                        .map(|tool| tool.function.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for ToolChoice {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct SpecificFunction<'a> {
+            name: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Specific<'a> {
+            #[serde(rename = "type")]
+            tool_type: &'static str,
+            function: SpecificFunction<'a>,
+        }
+
+        match self {
+            Self::Auto => serializer.serialize_str("auto"),
+            Self::None => serializer.serialize_str("none"),
+            Self::Required => serializer.serialize_str("required"),
+            Self::Specific { name } => Specific {
+                tool_type: "function",
+                function: SpecificFunction { name },
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ToolChoice {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Str(String),
+            Specific {
+                function: SpecificFunction,
+            },
+        }
+
+        #[derive(Deserialize)]
+        struct SpecificFunction {
+            name: String,
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Str(s) => match s.as_str() {
+                "auto" => Ok(Self::Auto),
+                "none" => Ok(Self::None),
+                "required" => Ok(Self::Required),
+                other => Err(serde::de::Error::custom(format!(
+                    "unknown tool choice `{other}`"
+                ))),
+            },
+            Repr::Specific { function } => Ok(Self::Specific {
+                name: function.name,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::tools::{ToolFunctionInfo, ToolType};
+
+    fn tool_info(name: &str) -> ToolInfo {
+        let schema_value = serde_json::json!({"type": "object"});
+        ToolInfo {
+            tool_type: ToolType::Function,
+            function: ToolFunctionInfo {
+                name: name.to_string(),
+                description: "a test tool".to_string(),
+                parameters: serde_json::from_value(schema_value).unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn serializes_auto_none_required_as_strings() {
+        assert_eq!(serde_json::to_string(&ToolChoice::Auto).unwrap(), "\"auto\"");
+        assert_eq!(serde_json::to_string(&ToolChoice::None).unwrap(), "\"none\"");
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Required).unwrap(),
+            "\"required\""
+        );
+    }
+
+    #[test]
+    fn serializes_specific_as_function_object() {
+        let json = serde_json::to_value(ToolChoice::specific("get_weather")).unwrap();
+        assert_eq!(json["type"], "function");
+        assert_eq!(json["function"]["name"], "get_weather");
+    }
+
+    #[test]
+    fn validate_accepts_registered_tool_name() {
+        let tools = vec![tool_info("get_weather")];
+        assert!(ToolChoice::specific("get_weather").validate(&tools).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_tool_name() {
+        let tools = vec![tool_info("get_weather")];
+        assert!(ToolChoice::specific("get_wether").validate(&tools).is_err());
+    }
+
+    #[test]
+    fn validate_ignores_non_specific_variants() {
+        let tools: Vec<ToolInfo> = vec![];
+        assert!(ToolChoice::Auto.validate(&tools).is_ok());
+        assert!(ToolChoice::None.validate(&tools).is_ok());
+        assert!(ToolChoice::Required.validate(&tools).is_ok());
+    }
+}
@@ -0,0 +1,71 @@
+use schemars::Schema;
+use serde_json::Value;
+
+use super::Result;
+
+/// Validates `arguments` against a tool's `parameters` schema, returning a human-readable
+/// error listing every missing/invalid property instead of a raw serde error.
+///
+/// Used by [`super::ToolHolder::call`] when a [`super::Tool`] opts in via
+/// [`super::Tool::VALIDATE_ARGUMENTS`]. Requires `jsonschema` 0.20, whose `Validator::validate`
+/// returns a `Result<(), ErrorIterator>` rather than the `iter_errors` method added in later
+/// releases.
+pub(crate) fn validate_arguments(schema: &Schema, arguments: &Value) -> Result<()> {
+    let schema_value = serde_json::to_value(schema)?;
+    let validator = jsonschema::validator_for(&schema_value)
+        .map_err(|err| format!("tool parameter schema is not valid draft-07: {err}"))?;
+
+    let errors = match validator.validate(arguments) {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors,
+    };
+
+    let problems: Vec<String> = errors
+        .map(|err| format!("`{}`: {err}", err.instance_path))
+        .collect();
+
+    Err(format!(
+        "tool arguments did not match the expected schema: {}",
+        problems.join("; ")
+    )
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn city_schema() -> Schema {
+        let value = json!({
+            "type": "object",
+            "properties": {
+                "city": { "type": "string" }
+            },
+            "required": ["city"],
+        });
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn accepts_arguments_matching_the_schema() {
+        let schema = city_schema();
+        assert!(validate_arguments(&schema, &json!({ "city": "Berlin" })).is_ok());
+    }
+
+    #[test]
+    fn reports_missing_required_property() {
+        let schema = city_schema();
+        let err = validate_arguments(&schema, &json!({})).unwrap_err();
+        assert!(err.to_string().contains("city"));
+    }
+
+    #[test]
+    fn reports_wrong_property_type() {
+        let schema = city_schema();
+        let err = validate_arguments(&schema, &json!({ "city": 5 })).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("city"));
+        assert!(message.contains("string"));
+    }
+}